@@ -13,6 +13,17 @@ pub(crate) type WaitableChildProcess = std::pin::Pin<
     Box<dyn futures::Future<Output = Result<std::process::Output, std::io::Error>> + Send + Sync>,
 >;
 
+/// Sends byte chunks to a streaming child process's stdin.
+pub type InputChannel = tokio::sync::mpsc::Sender<Vec<u8>>;
+/// Receives byte chunks from a streaming child process's stdout or stderr.
+pub type OutputChannel = tokio::sync::mpsc::Receiver<Vec<u8>>;
+
+/// Maximum number of in-flight chunks buffered per streaming channel before
+/// backpressure is applied to the producer.
+const STREAM_CHANNEL_CAPACITY: usize = 32;
+/// Maximum number of bytes read from the child per streaming output chunk.
+const STREAM_CHUNK_SIZE: usize = 8 * 1024;
+
 /// Tracks a child process being awaited.
 pub struct ChildProcess {
     /// If available, the process ID of the child.
@@ -43,6 +54,58 @@ impl ChildProcess {
         self.pid
     }
 
+    /// Wraps a child process for streaming I/O instead of buffering its output.
+    ///
+    /// Unlike `new`, this spawns background tasks that pump `child`'s piped stdin,
+    /// stdout, and stderr through bounded channels, so a caller can feed stdin
+    /// incrementally and consume output as it arrives instead of waiting for exit.
+    /// Backpressure is applied once a channel's buffer fills. `wait` still resolves
+    /// to an exit status, but the returned `ProcessWaitResult::Completed`'s
+    /// `stdout`/`stderr` are always empty, since output is delivered via the
+    /// returned channels instead of being accumulated in memory.
+    pub fn spawn_streaming(
+        pid: Option<sys::process::ProcessId>,
+        mut child: sys::process::Child,
+    ) -> (Self, InputChannel, OutputChannel, OutputChannel) {
+        #[cfg(windows)]
+        let kill_handle = duplicate_handle(child.as_raw_handle());
+
+        let (stdin_tx, mut stdin_rx) = tokio::sync::mpsc::channel::<Vec<u8>>(STREAM_CHANNEL_CAPACITY);
+        let (stdout_tx, stdout_rx) = tokio::sync::mpsc::channel::<Vec<u8>>(STREAM_CHANNEL_CAPACITY);
+        let (stderr_tx, stderr_rx) = tokio::sync::mpsc::channel::<Vec<u8>>(STREAM_CHANNEL_CAPACITY);
+
+        if let Some(mut stdin) = child.stdin.take() {
+            tokio::spawn(async move {
+                use tokio::io::AsyncWriteExt;
+                while let Some(chunk) = stdin_rx.recv().await {
+                    if stdin.write_all(&chunk).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        if let Some(stdout) = child.stdout.take() {
+            tokio::spawn(pump_output(stdout, stdout_tx));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            tokio::spawn(pump_output(stderr, stderr_tx));
+        }
+
+        let exec_future: WaitableChildProcess = Box::pin(async move {
+            let status = child.wait().await?;
+            Ok(std::process::Output { status, stdout: Vec::new(), stderr: Vec::new() })
+        });
+
+        let child_process = Self {
+            pid,
+            exec_future,
+            #[cfg(windows)]
+            kill_handle,
+        };
+
+        (child_process, stdin_tx, stdout_rx, stderr_rx)
+    }
+
     /// Duplicates the process handle for termination use on Windows.
     #[cfg(windows)]
     pub fn duplicate_kill_handle(&self) -> Option<OwnedHandle> {
@@ -53,9 +116,16 @@ impl ChildProcess {
     /// Waits for the process to exit.
     ///
     /// If a cancellation token is provided and triggered, the process will be killed.
+    /// If a timeout is provided and elapses before the process exits, the process will
+    /// be killed and `ProcessWaitResult::TimedOut` will be returned.
+    ///
+    /// If the process is stopped (e.g. via `SIGTSTP`), `ProcessWaitResult::Stopped` is
+    /// returned without killing or otherwise disturbing the child; the caller may
+    /// resume it with `cont` and call `wait` again to keep waiting for completion.
     pub async fn wait(
         &mut self,
         cancel_token: Option<CancellationToken>,
+        timeout: Option<std::time::Duration>,
     ) -> Result<ProcessWaitResult, error::Error> {
         #[allow(unused_mut, reason = "only mutated on some platforms")]
         let mut sigtstp = sys::signal::tstp_signal_listener()?;
@@ -70,6 +140,14 @@ impl ChildProcess {
         };
         tokio::pin!(cancelled);
 
+        let timed_out = async {
+            match timeout {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::pin!(timed_out);
+
         #[allow(clippy::ignored_unit_patterns)]
         loop {
             tokio::select! {
@@ -80,6 +158,10 @@ impl ChildProcess {
                     self.kill();
                     break Ok(ProcessWaitResult::Cancelled)
                 },
+                _ = &mut timed_out => {
+                    self.kill();
+                    break Ok(ProcessWaitResult::TimedOut)
+                },
                 _ = sigtstp.recv() => {
                     break Ok(ProcessWaitResult::Stopped)
                 },
@@ -97,6 +179,20 @@ impl ChildProcess {
         }
     }
 
+    /// Resumes a stopped process by sending it `SIGCONT`.
+    ///
+    /// Use this after `wait` returns `ProcessWaitResult::Stopped` to resume a
+    /// foreground job suspended by `SIGTSTP`, then call `wait` again to continue
+    /// waiting for it to complete. Like `kill`, this only signals the tracked
+    /// PID; it does not address a separate process group, since we never place
+    /// the child in one of its own.
+    #[cfg(unix)]
+    pub fn cont(&self) {
+        let Some(pid) = self.pid else { return };
+
+        let _ = nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid), nix::sys::signal::Signal::SIGCONT);
+    }
+
     /// Terminates the process if we have a PID.
     fn kill(&self) {
         let Some(pid) = self.pid else { return };
@@ -158,12 +254,38 @@ fn duplicate_handle(handle: RawHandle) -> Option<OwnedHandle> {
     Some(unsafe { OwnedHandle::from_raw_handle(out_handle) })
 }
 
+/// Reads `source` in bounded chunks and forwards each to `tx`, applying
+/// backpressure once the channel fills, until EOF, a read error, or the
+/// receiver is dropped.
+async fn pump_output<R: tokio::io::AsyncRead + Unpin>(
+    mut source: R,
+    tx: tokio::sync::mpsc::Sender<Vec<u8>>,
+) {
+    use tokio::io::AsyncReadExt;
+
+    let mut buf = vec![0u8; STREAM_CHUNK_SIZE];
+    loop {
+        match source.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                if tx.send(buf[..n].to_vec()).await.is_err() {
+                    break;
+                }
+            },
+        }
+    }
+}
+
 /// Represents the result of waiting for an executing process.
 pub enum ProcessWaitResult {
     /// The process completed.
     Completed(std::process::Output),
-    /// The process stopped and has not yet completed.
+    /// The process stopped (e.g. via `SIGTSTP`) and has not yet completed. The
+    /// child is left alive but suspended; resume it with `ChildProcess::cont` and call
+    /// `wait` again to keep waiting for it.
     Stopped,
     /// The process was killed due to cancellation.
     Cancelled,
+    /// The process was killed because it did not exit before the requested timeout.
+    TimedOut,
 }
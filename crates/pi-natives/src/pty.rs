@@ -39,6 +39,19 @@ pub struct PtyStartOptions<'env> {
 	pub cols:       Option<u16>,
 	/// PTY row count.
 	pub rows:       Option<u16>,
+	/// Terminal-output filter applied to decoded chunks before they reach `on_chunk`.
+	pub filter:     Option<PtyFilterMode>,
+	/// Byte cap for an opt-in bounded scrollback buffer returned as `captured`.
+	pub capture:    Option<u32>,
+}
+
+/// Selects a terminal-output filter to apply to PTY output before it reaches JS.
+#[napi(string_enum)]
+pub enum PtyFilterMode {
+	/// Pass output through unmodified.
+	None,
+	/// Strip ANSI escape sequences, leaving plain monochrome text.
+	Monochrome,
 }
 
 /// Result of a PTY command run.
@@ -50,6 +63,8 @@ pub struct PtyRunResult {
 	pub cancelled: bool,
 	/// Whether command timed out.
 	pub timed_out: bool,
+	/// Captured output tail, present when `PtyStartOptions.capture` was set.
+	pub captured:  Option<String>,
 }
 
 #[derive(Clone)]
@@ -59,6 +74,164 @@ struct PtyRunConfig {
 	env:     Option<HashMap<String, String>>,
 	cols:    u16,
 	rows:    u16,
+	filter:  Option<PtyFilterMode>,
+	capture: Option<u32>,
+}
+
+/// Bounded ring buffer retaining the most recently captured output, dropping
+/// the oldest bytes once the configured cap is exceeded.
+struct CaptureBuffer {
+	cap: usize,
+	buf: String,
+}
+
+impl CaptureBuffer {
+	fn new(cap: usize) -> Self {
+		Self { cap, buf: String::new() }
+	}
+
+	fn push(&mut self, chunk: &str) {
+		self.buf.push_str(chunk);
+		if self.buf.len() > self.cap {
+			let excess = self.buf.len() - self.cap;
+			let trim_at = self
+				.buf
+				.char_indices()
+				.map(|(i, _)| i)
+				.find(|&i| i >= excess)
+				.unwrap_or(self.buf.len());
+			self.buf.drain(..trim_at);
+		}
+	}
+}
+
+/// Rewrites or strips PTY output before it reaches the `on_chunk` callback.
+///
+/// Implementations are stateful: escape sequences can be split across `read()`
+/// boundaries, so a filter must buffer a partial trailing escape (a byte sequence
+/// starting with ESC that has not yet terminated) across calls and emit it only
+/// once it is complete, mirroring how `filterm` intercepts child terminal data.
+trait Filter: Send {
+	fn transform(&mut self, chunk: &str, out: &mut String);
+
+	/// Flushes any buffered partial state as literal text. Called once the
+	/// underlying output stream has ended, so a trailing unterminated escape
+	/// sequence is not silently dropped.
+	fn flush(&mut self, out: &mut String) {
+		let _ = out;
+	}
+}
+
+fn build_filter(mode: Option<PtyFilterMode>) -> Option<Box<dyn Filter>> {
+	match mode? {
+		PtyFilterMode::None => None,
+		PtyFilterMode::Monochrome => Some(Box::new(MonochromeFilter::default())),
+	}
+}
+
+const ESC: u8 = 0x1b;
+
+/// Cap on a buffered, not-yet-terminated escape sequence. Real terminals cap
+/// sequence length too; once an unterminated sequence grows past this, it is
+/// almost certainly a bare `ESC` in plain data or a truncated/malformed
+/// sequence, so it is flushed through as literal text instead of being
+/// buffered forever.
+const MAX_PENDING_ESCAPE_BYTES: usize = 4 * 1024;
+
+/// Strips ANSI escape sequences (CSI, OSC/DCS/SOS/PM/APC, and simple two- and
+/// three-byte escapes), leaving plain text.
+#[derive(Default)]
+struct MonochromeFilter {
+	/// A trailing escape sequence carried over from a previous `transform` call
+	/// because it had not yet terminated.
+	pending: String,
+}
+
+impl Filter for MonochromeFilter {
+	fn transform(&mut self, chunk: &str, out: &mut String) {
+		let mut buf = std::mem::take(&mut self.pending);
+		buf.push_str(chunk);
+
+		let mut rest = buf.as_str();
+		loop {
+			let Some(idx) = rest.find(ESC as char) else {
+				out.push_str(rest);
+				return;
+			};
+			out.push_str(&rest[..idx]);
+			let tail = &rest[idx..];
+			match escape_sequence_len(tail) {
+				Some(len) => rest = &tail[len..],
+				None if tail.len() > MAX_PENDING_ESCAPE_BYTES => {
+					// Never terminated within a reasonable bound; stop waiting and
+					// emit it as literal text so the rest of the stream isn't withheld.
+					out.push_str(tail);
+					return;
+				},
+				None => {
+					self.pending = tail.to_string();
+					return;
+				},
+			}
+		}
+	}
+
+	fn flush(&mut self, out: &mut String) {
+		out.push_str(&std::mem::take(&mut self.pending));
+	}
+}
+
+/// Returns the length in bytes of the complete ANSI escape sequence starting at
+/// the beginning of `s`, or `None` if `s` does not yet contain enough bytes to
+/// know where the sequence ends.
+fn escape_sequence_len(s: &str) -> Option<usize> {
+	let bytes = s.as_bytes();
+	debug_assert_eq!(bytes.first(), Some(&ESC));
+
+	match bytes.get(1) {
+		None => None,
+		// CSI: ESC '[' parameter/intermediate bytes, terminated by a final byte in 0x40-0x7E.
+		Some(b'[') => {
+			let mut i = 2;
+			loop {
+				match bytes.get(i) {
+					None => return None,
+					Some(&b) if (0x40..=0x7e).contains(&b) => return Some(i + 1),
+					Some(_) => i += 1,
+				}
+			}
+		},
+		// OSC: ESC ']' ... terminated by BEL or ST (ESC '\').
+		Some(b']') => string_sequence_len(bytes, true),
+		// DCS/SOS/PM/APC: ESC 'P'/'X'/'^'/'_' ... terminated by ST only (no BEL).
+		Some(b'P' | b'X' | b'^' | b'_') => string_sequence_len(bytes, false),
+		// Character-set designation: ESC '(' / ')' / '*' / '+' plus one charset byte.
+		// The charset byte is normally ASCII, but measure its real UTF-8 width so a
+		// stray multi-byte character here can't produce a length that splits a char.
+		Some(b'(' | b')' | b'*' | b'+') => s[2..].chars().next().map(|c| 2 + c.len_utf8()),
+		// A simple two-byte escape, e.g. ESC 'c' (RIS) or ESC '=' (DECKPAM).
+		Some(_) => Some(1 + s[1..].chars().next().map_or(1, char::len_utf8)),
+	}
+}
+
+/// Scans a "string" escape sequence (OSC/DCS/SOS/PM/APC), whose body starts at
+/// `bytes[2]`, for its terminator: BEL (only when `allow_bel` is set, i.e. for
+/// OSC) or ST (`ESC '\\'`). Returns the sequence length in bytes, or `None` if
+/// the terminator has not yet arrived.
+fn string_sequence_len(bytes: &[u8], allow_bel: bool) -> Option<usize> {
+	let mut i = 2;
+	loop {
+		match bytes.get(i) {
+			None => return None,
+			Some(b'\x07') if allow_bel => return Some(i + 1),
+			Some(&ESC) => match bytes.get(i + 1) {
+				None => return None,
+				Some(b'\\') => return Some(i + 2),
+				Some(_) => i += 1,
+			},
+			Some(_) => i += 1,
+		}
+	}
 }
 
 enum ReaderEvent {
@@ -119,6 +292,8 @@ impl PtySession {
 			env:     options.env,
 			cols:    options.cols.unwrap_or(120).clamp(20, 400),
 			rows:    options.rows.unwrap_or(40).clamp(5, 200),
+			filter:  options.filter,
+			capture: options.capture,
 		};
 		let ct = task::CancelToken::new(options.timeout_ms, options.signal);
 		let core = Arc::clone(&self.core);
@@ -223,6 +398,8 @@ fn run_pty_sync(
 	control_rx: mpsc::Receiver<ControlMessage>,
 	ct: task::CancelToken,
 ) -> Result<PtyRunResult> {
+	let mut filter = build_filter(config.filter);
+	let mut capture = config.capture.map(|cap| CaptureBuffer::new(cap as usize));
 	let pty_system = native_pty_system();
 	let pair = pty_system
 		.openpty(PtySize {
@@ -367,7 +544,9 @@ fn run_pty_sync(
 
 		for _ in 0..READER_EVENTS_PER_TICK {
 			match reader_rx.try_recv() {
-				Ok(ReaderEvent::Chunk(chunk)) => emit_chunk(&chunk, on_chunk.as_ref()),
+				Ok(ReaderEvent::Chunk(chunk)) => {
+					emit_filtered_chunk(&chunk, filter.as_mut(), capture.as_mut(), on_chunk.as_ref());
+				},
 				Ok(ReaderEvent::Done) => {
 					reader_done = true;
 					break;
@@ -422,7 +601,9 @@ fn run_pty_sync(
 		let finalize_deadline = Instant::now() + FINAL_READER_DRAIN_TIMEOUT;
 		while Instant::now() < finalize_deadline {
 			match reader_rx.try_recv() {
-				Ok(ReaderEvent::Chunk(chunk)) => emit_chunk(&chunk, on_chunk.as_ref()),
+				Ok(ReaderEvent::Chunk(chunk)) => {
+					emit_filtered_chunk(&chunk, filter.as_mut(), capture.as_mut(), on_chunk.as_ref());
+				},
 				Ok(ReaderEvent::Done) => {
 					reader_done = true;
 					break;
@@ -441,7 +622,20 @@ fn run_pty_sync(
 	if reader_done {
 		let _ = reader_thread.join();
 	}
-	Ok(PtyRunResult { exit_code, cancelled, timed_out })
+
+	if let Some(filter) = filter.as_mut() {
+		let mut out = String::new();
+		filter.flush(&mut out);
+		if !out.is_empty() {
+			if let Some(capture) = capture.as_mut() {
+				capture.push(&out);
+			}
+			emit_chunk(&out, on_chunk.as_ref());
+		}
+	}
+
+	let captured = capture.map(|capture| capture.buf);
+	Ok(PtyRunResult { exit_code, cancelled, timed_out, captured })
 }
 
 fn emit_chunk(text: &str, callback: Option<&ThreadsafeFunction<String>>) {
@@ -449,3 +643,106 @@ fn emit_chunk(text: &str, callback: Option<&ThreadsafeFunction<String>>) {
 		callback.call(Ok(text.to_string()), ThreadsafeFunctionCallMode::NonBlocking);
 	}
 }
+
+fn emit_filtered_chunk(
+	chunk: &str,
+	filter: Option<&mut Box<dyn Filter>>,
+	capture: Option<&mut CaptureBuffer>,
+	callback: Option<&ThreadsafeFunction<String>>,
+) {
+	let mut filtered = String::new();
+	let out = match filter {
+		Some(filter) => {
+			filter.transform(chunk, &mut filtered);
+			filtered.as_str()
+		},
+		None => chunk,
+	};
+	if out.is_empty() {
+		return;
+	}
+	if let Some(capture) = capture {
+		capture.push(out);
+	}
+	emit_chunk(out, callback);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn monochrome_filter_reassembles_split_csi_escape() {
+		let mut filter = MonochromeFilter::default();
+		let mut out = String::new();
+		filter.transform("before \x1b[3", &mut out);
+		assert_eq!(out, "before ");
+		out.clear();
+		filter.transform("1mred\x1b[0m after", &mut out);
+		assert_eq!(out, "red after");
+	}
+
+	#[test]
+	fn monochrome_filter_strips_terminated_csi_and_osc() {
+		let mut filter = MonochromeFilter::default();
+		let mut out = String::new();
+		filter.transform("\x1b[31mred\x1b[0m \x1b]0;title\x07done", &mut out);
+		assert_eq!(out, "red done");
+	}
+
+	#[test]
+	fn monochrome_filter_flushes_incomplete_escape_at_eof() {
+		let mut filter = MonochromeFilter::default();
+		let mut out = String::new();
+		filter.transform("tail\x1b[3", &mut out);
+		assert_eq!(out, "tail");
+		out.clear();
+		filter.flush(&mut out);
+		assert_eq!(out, "\x1b[3");
+	}
+
+	#[test]
+	fn monochrome_filter_bounds_unterminated_escape() {
+		let mut filter = MonochromeFilter::default();
+		let mut out = String::new();
+		let huge = "\x1b[".to_string() + &"9".repeat(MAX_PENDING_ESCAPE_BYTES + 1);
+		filter.transform(&huge, &mut out);
+		assert_eq!(out, huge);
+	}
+
+	#[test]
+	fn escape_sequence_len_two_byte_fallback() {
+		assert_eq!(escape_sequence_len("\x1bc"), Some(2));
+	}
+
+	#[test]
+	fn escape_sequence_len_charset_designator() {
+		assert_eq!(escape_sequence_len("\x1b(B"), Some(3));
+		assert_eq!(escape_sequence_len("\x1b("), None);
+	}
+
+	#[test]
+	fn capture_buffer_enforces_byte_cap_on_char_boundary() {
+		let mut buf = CaptureBuffer::new(4);
+		buf.push("héllo");
+		assert!(buf.buf.len() <= 4, "trimmed buffer must not exceed the byte cap");
+		assert!(std::str::from_utf8(buf.buf.as_bytes()).is_ok());
+	}
+
+	#[test]
+	fn capture_buffer_zero_cap_drains_to_empty() {
+		let mut buf = CaptureBuffer::new(0);
+		buf.push("hello");
+		assert_eq!(buf.buf, "");
+	}
+
+	#[test]
+	fn capture_buffer_keeps_tail_of_filtered_output() {
+		let mut filter = MonochromeFilter::default();
+		let mut capture = CaptureBuffer::new(5);
+		let mut out = String::new();
+		filter.transform("\x1b[31mhello world\x1b[0m", &mut out);
+		capture.push(&out);
+		assert_eq!(capture.buf, "world");
+	}
+}